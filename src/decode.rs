@@ -0,0 +1,220 @@
+use crate::encoder;
+use crate::Json;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum DecodeError {
+    ExpectedError(String, String),
+    MissingFieldError(String),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::ExpectedError(expected, found) => {
+                write!(f, "expected {}, found {}", expected, found)
+            }
+            DecodeError::MissingFieldError(name) => write!(f, "missing field '{}'", name),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+pub trait FromJson: Sized {
+    fn from_json(value: &Json) -> Result<Self, DecodeError>;
+}
+
+fn expected(expected: &str, found: &Json) -> DecodeError {
+    DecodeError::ExpectedError(expected.to_string(), encoder::to_string(found))
+}
+
+impl FromJson for bool {
+    fn from_json(value: &Json) -> Result<Self, DecodeError> {
+        match value {
+            Json::Bool(b) => Ok(*b),
+            other => Err(expected("Bool", other)),
+        }
+    }
+}
+
+/// Unlike the float impls below, an integer can't represent every `f64`, so
+/// a bare `as` cast would silently saturate (`1e20 as u8 == 255`) or
+/// truncate (`-5.0 as u8 == 0`) out-of-range input instead of reporting it.
+/// Round-tripping the cast value back through `f64` catches both that and
+/// fractional values (`3.5` into an `i32`).
+macro_rules! impl_from_json_for_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl FromJson for $ty {
+                fn from_json(value: &Json) -> Result<Self, DecodeError> {
+                    match value {
+                        Json::Number(n) => {
+                            let casted = *n as $ty;
+                            // `MAX as f64` rounds up to 2^63/2^64 for the
+                            // 64-bit types, so saturation there still
+                            // round-trips; bound the input explicitly too.
+                            if casted as f64 == *n && *n < <$ty>::MAX as f64 + 1.0 {
+                                Ok(casted)
+                            } else {
+                                Err(expected(stringify!($ty), value))
+                            }
+                        }
+                        other => Err(expected("Number", other)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_json_for_float {
+    ($($ty:ty),*) => {
+        $(
+            impl FromJson for $ty {
+                fn from_json(value: &Json) -> Result<Self, DecodeError> {
+                    match value {
+                        Json::Number(n) => Ok(*n as $ty),
+                        other => Err(expected("Number", other)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_json_for_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_from_json_for_float!(f32, f64);
+
+impl FromJson for String {
+    fn from_json(value: &Json) -> Result<Self, DecodeError> {
+        match value {
+            Json::String(s) => Ok(s.clone()),
+            other => Err(expected("String", other)),
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(value: &Json) -> Result<Self, DecodeError> {
+        match value {
+            Json::Array(items) => items.iter().map(T::from_json).collect(),
+            other => Err(expected("Array", other)),
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json(value: &Json) -> Result<Self, DecodeError> {
+        match value {
+            Json::Null => Ok(None),
+            other => T::from_json(other).map(Some),
+        }
+    }
+}
+
+impl<T: FromJson> FromJson for HashMap<String, T> {
+    fn from_json(value: &Json) -> Result<Self, DecodeError> {
+        match value {
+            Json::Object(fields) => fields
+                .iter()
+                .map(|(key, value)| T::from_json(value).map(|decoded| (key.clone(), decoded)))
+                .collect(),
+            other => Err(expected("Object", other)),
+        }
+    }
+}
+
+/// Looks up `name` in a `Json::Object`, failing with `MissingFieldError` if
+/// the object has no such key and `ExpectedError` if `value` isn't an object.
+pub fn field<'a>(value: &'a Json, name: &str) -> Result<&'a Json, DecodeError> {
+    match value {
+        Json::Object(fields) => fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+            .ok_or_else(|| DecodeError::MissingFieldError(name.to_string())),
+        other => Err(expected("Object", other)),
+    }
+}
+
+/// Decodes the named field of `value` as `T`.
+pub fn decode_field<T: FromJson>(value: &Json, name: &str) -> Result<T, DecodeError> {
+    T::from_json(field(value, name)?)
+}
+
+/// Decodes the named field of `value` as `T`, treating a missing key the
+/// same as a present `null`.
+pub fn decode_opt_field<T: FromJson>(value: &Json, name: &str) -> Result<Option<T>, DecodeError> {
+    match field(value, name) {
+        Ok(found) => Option::<T>::from_json(found),
+        Err(DecodeError::MissingFieldError(_)) => Ok(None),
+        Err(other) => Err(other),
+    }
+}
+
+/// Decodes a whole `Json` value as `T`, the typed counterpart to parsing.
+pub fn decode<T: FromJson>(value: &Json) -> Result<T, DecodeError> {
+    T::from_json(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, decode_field, decode_opt_field, DecodeError, FromJson};
+    use crate::Json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_decode_scalars() {
+        assert_eq!(decode::<bool>(&Json::Bool(true)), Ok(true));
+        assert_eq!(decode::<i64>(&Json::Number(42.0)), Ok(42));
+        assert_eq!(
+            decode::<String>(&Json::String("hi".into())),
+            Ok("hi".to_string())
+        );
+    }
+
+    #[test]
+    fn test_decode_integer_rejects_out_of_range_and_fractional_numbers() {
+        assert!(decode::<u8>(&Json::Number(1e20)).is_err());
+        assert!(decode::<u8>(&Json::Number(-5.0)).is_err());
+        assert!(decode::<i32>(&Json::Number(3.5)).is_err());
+        assert_eq!(decode::<u8>(&Json::Number(255.0)), Ok(255));
+    }
+
+    #[test]
+    fn test_decode_vec_and_map() {
+        let array = Json::Array(vec![Json::Number(1.0), Json::Number(2.0)]);
+        assert_eq!(decode::<Vec<i64>>(&array), Ok(vec![1, 2]));
+
+        let object = Json::Object(vec![("a".into(), Json::Number(1.0))]);
+        let mut expected = HashMap::new();
+        expected.insert("a".to_string(), 1i64);
+        assert_eq!(decode::<HashMap<String, i64>>(&object), Ok(expected));
+    }
+
+    #[test]
+    fn test_decode_option() {
+        assert_eq!(decode::<Option<i64>>(&Json::Null), Ok(None));
+        assert_eq!(decode::<Option<i64>>(&Json::Number(7.0)), Ok(Some(7)));
+        assert_eq!(
+            Option::<i64>::from_json(&Json::Array(vec![])),
+            Err(DecodeError::ExpectedError("Number".into(), "[]".into()))
+        );
+    }
+
+    #[test]
+    fn test_decode_fields() {
+        let object = Json::Object(vec![("name".into(), Json::String("ara".into()))]);
+        assert_eq!(
+            decode_field::<String>(&object, "name"),
+            Ok("ara".to_string())
+        );
+        assert_eq!(
+            decode_field::<i64>(&object, "age"),
+            Err(DecodeError::MissingFieldError("age".into()))
+        );
+        assert_eq!(decode_opt_field::<i64>(&object, "age"), Ok(None));
+    }
+}
@@ -0,0 +1,156 @@
+use crate::Json;
+
+/// JSON has no syntax for non-finite numbers, so `f64::INFINITY`,
+/// `f64::NEG_INFINITY`, and `NaN` are encoded as `null` instead of `inf`,
+/// `-inf`, or `NaN`, which would not parse back as JSON at all.
+fn write_number(n: f64, out: &mut String) {
+    if n.is_finite() {
+        out.push_str(&n.to_string());
+    } else {
+        out.push_str("null");
+    }
+}
+
+pub fn to_string(json: &Json) -> String {
+    let mut out = String::new();
+    write_compact(json, &mut out);
+    out
+}
+
+pub fn to_string_pretty(json: &Json, indent: usize) -> String {
+    let mut out = String::new();
+    write_pretty(json, indent, 0, &mut out);
+    out
+}
+
+fn write_compact(json: &Json, out: &mut String) {
+    match json {
+        Json::Null => out.push_str("null"),
+        Json::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Json::Number(n) => write_number(*n, out),
+        Json::String(s) => write_escaped_string(s, out),
+        Json::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_compact(item, out);
+            }
+            out.push(']');
+        }
+        Json::Object(fields) => {
+            out.push('{');
+            for (i, (key, value)) in fields.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_escaped_string(key, out);
+                out.push(':');
+                write_compact(value, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_pretty(json: &Json, indent: usize, depth: usize, out: &mut String) {
+    match json {
+        Json::Array(items) if !items.is_empty() => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push('\n');
+                push_indent(out, indent, depth + 1);
+                write_pretty(item, indent, depth + 1, out);
+            }
+            out.push('\n');
+            push_indent(out, indent, depth);
+            out.push(']');
+        }
+        Json::Object(fields) if !fields.is_empty() => {
+            out.push('{');
+            for (i, (key, value)) in fields.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push('\n');
+                push_indent(out, indent, depth + 1);
+                write_escaped_string(key, out);
+                out.push_str(": ");
+                write_pretty(value, indent, depth + 1, out);
+            }
+            out.push('\n');
+            push_indent(out, indent, depth);
+            out.push('}');
+        }
+        _ => write_compact(json, out),
+    }
+}
+
+fn push_indent(out: &mut String, indent: usize, depth: usize) {
+    for _ in 0..indent * depth {
+        out.push(' ');
+    }
+}
+
+fn write_escaped_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_string, to_string_pretty};
+    use crate::Json;
+
+    #[test]
+    fn test_to_string_scalars() {
+        assert_eq!(to_string(&Json::Null), "null");
+        assert_eq!(to_string(&Json::Bool(true)), "true");
+        assert_eq!(to_string(&Json::Number(123.0)), "123");
+        assert_eq!(to_string(&Json::Number(-0.383)), "-0.383");
+        assert_eq!(to_string(&Json::String("a\nb".into())), "\"a\\nb\"");
+    }
+
+    #[test]
+    fn test_to_string_non_finite_numbers_as_null() {
+        assert_eq!(to_string(&Json::Number(f64::INFINITY)), "null");
+        assert_eq!(to_string(&Json::Number(f64::NEG_INFINITY)), "null");
+        assert_eq!(to_string(&Json::Number(f64::NAN)), "null");
+    }
+
+    #[test]
+    fn test_to_string_array_and_object() {
+        let array = Json::Array(vec![Json::Number(1.0), Json::Null, Json::Bool(false)]);
+        assert_eq!(to_string(&array), "[1,null,false]");
+
+        let object = Json::Object(vec![("key".into(), Json::String("value".into()))]);
+        assert_eq!(to_string(&object), "{\"key\":\"value\"}");
+    }
+
+    #[test]
+    fn test_to_string_pretty() {
+        let object = Json::Object(vec![(
+            "outer".into(),
+            Json::Array(vec![Json::Number(1.0), Json::Number(2.0)]),
+        )]);
+        assert_eq!(
+            to_string_pretty(&object, 2),
+            "{\n  \"outer\": [\n    1,\n    2\n  ]\n}"
+        );
+    }
+}
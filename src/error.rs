@@ -0,0 +1,121 @@
+use std::fmt;
+use std::io;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// The input ended while a value was still expected.
+    EOFWhileParsingValue,
+    /// Something that looked like a number didn't parse as one.
+    InvalidNumber,
+    /// An object key was expected but the next value isn't a string.
+    KeyMustBeAString,
+    /// Arrays/objects nested past the parser's fixed depth limit.
+    RecursionLimitExceeded,
+    /// A complete value was parsed but characters remain afterwards.
+    TrailingCharacters,
+    /// The input doesn't match any JSON production at this position.
+    UnexpectedToken,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub code: ErrorCode,
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl ParseError {
+    /// Builds a `ParseError` from the byte offset at which `remaining`
+    /// starts within `source`, translating it into a 1-based line/column.
+    pub(crate) fn at(code: ErrorCode, source: &str, remaining: &str) -> Self {
+        let offset = source.len() - remaining.len();
+        let (line, column) = line_column(source, offset);
+        ParseError {
+            code,
+            offset,
+            line,
+            column,
+        }
+    }
+}
+
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let consumed = &source[..offset];
+    let line = consumed.matches('\n').count() + 1;
+    let column = match consumed.rfind('\n') {
+        Some(newline_offset) => offset - newline_offset,
+        None => offset + 1,
+    };
+    (line, column)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} at line {} column {}",
+            self.code, self.line, self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Everything that can go wrong while reading a JSON document from disk:
+/// the file couldn't be read, or it could be read but didn't parse.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        ReadError::Parse(err)
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "{}", err),
+            ReadError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{line_column, ErrorCode, ParseError};
+
+    #[test]
+    fn test_line_column_first_line() {
+        assert_eq!(line_column("abc", 2), (1, 3));
+    }
+
+    #[test]
+    fn test_line_column_after_newline() {
+        assert_eq!(line_column("ab\ncd", 4), (2, 2));
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let err = ParseError::at(ErrorCode::TrailingCharacters, "42 43", " 43");
+        assert_eq!(err.offset, 2);
+        assert_eq!(err.line, 1);
+        assert_eq!(err.column, 3);
+        assert_eq!(
+            err.to_string(),
+            "TrailingCharacters at line 1 column 3"
+        );
+    }
+}
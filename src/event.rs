@@ -0,0 +1,272 @@
+use crate::{json_bool, json_null, json_number, string_literal, Json};
+
+/// A single step of the current path into the document, from the root down
+/// to whatever value the parser is positioned at.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StackElement {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum JsonEvent {
+    ObjectStart,
+    ObjectEnd,
+    ArrayStart,
+    ArrayEnd,
+    BooleanValue(bool),
+    NumberValue(f64),
+    StringValue(String),
+    NullValue,
+    Error(String),
+}
+
+#[derive(Debug)]
+enum SeqState {
+    /// The container was just opened; nothing has been read yet, so an
+    /// immediate close (`]`/`}`) is an empty container.
+    Start,
+    /// A comma was just consumed; a close here would mean a trailing comma.
+    AfterComma,
+    /// A value was just completed; expect a comma or a close next.
+    AfterValue,
+}
+
+#[derive(Debug)]
+enum Frame {
+    Array { next_index: usize, state: SeqState },
+    Object { state: SeqState },
+}
+
+/// A pull parser that walks a JSON document and yields one [`JsonEvent`] at
+/// a time, without ever materializing a full [`Json`] tree. `stack()` exposes
+/// the current path so a consumer can ignore branches it doesn't care about.
+pub struct Parser<'a> {
+    input: &'a str,
+    frames: Vec<Frame>,
+    stack: Vec<StackElement>,
+    started: bool,
+    finished: bool,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Parser {
+            input,
+            frames: Vec::new(),
+            stack: Vec::new(),
+            started: false,
+            finished: false,
+        }
+    }
+
+    pub fn stack(&self) -> &[StackElement] {
+        &self.stack
+    }
+
+    fn error(&mut self, message: impl Into<String>) -> Option<JsonEvent> {
+        self.finished = true;
+        Some(JsonEvent::Error(message.into()))
+    }
+
+    fn parse_value(&mut self) -> Option<JsonEvent> {
+        self.input = self.input.trim_start();
+
+        if let Some(rest) = self.input.strip_prefix('{') {
+            self.input = rest;
+            self.frames.push(Frame::Object {
+                state: SeqState::Start,
+            });
+            return Some(JsonEvent::ObjectStart);
+        }
+        if let Some(rest) = self.input.strip_prefix('[') {
+            self.input = rest;
+            self.frames.push(Frame::Array {
+                next_index: 0,
+                state: SeqState::Start,
+            });
+            return Some(JsonEvent::ArrayStart);
+        }
+        if let Ok((rest, Json::Null)) = json_null(self.input) {
+            self.input = rest;
+            return Some(JsonEvent::NullValue);
+        }
+        if let Ok((rest, Json::Bool(b))) = json_bool(self.input) {
+            self.input = rest;
+            return Some(JsonEvent::BooleanValue(b));
+        }
+        if let Ok((rest, s)) = string_literal(self.input) {
+            self.input = rest;
+            return Some(JsonEvent::StringValue(s));
+        }
+        if let Ok((rest, Json::Number(n))) = json_number(self.input) {
+            self.input = rest;
+            return Some(JsonEvent::NumberValue(n));
+        }
+
+        self.error(format!("expected a value, found {:?}", self.input))
+    }
+}
+
+impl<'a> Iterator for Parser<'a> {
+    type Item = JsonEvent;
+
+    fn next(&mut self) -> Option<JsonEvent> {
+        if self.finished {
+            return None;
+        }
+
+        loop {
+            self.input = self.input.trim_start();
+
+            if let Some(frame) = self.frames.last() {
+                let after_value = matches!(
+                    frame,
+                    Frame::Array {
+                        state: SeqState::AfterValue,
+                        ..
+                    } | Frame::Object {
+                        state: SeqState::AfterValue
+                    }
+                );
+                if after_value {
+                    self.stack.pop();
+                    let close = match frame {
+                        Frame::Array { .. } => ']',
+                        Frame::Object { .. } => '}',
+                    };
+                    if let Some(rest) = self.input.strip_prefix(close) {
+                        self.input = rest;
+                        self.frames.pop();
+                        return Some(if close == ']' {
+                            JsonEvent::ArrayEnd
+                        } else {
+                            JsonEvent::ObjectEnd
+                        });
+                    }
+                    let Some(rest) = self.input.strip_prefix(',') else {
+                        return self.error(format!("expected ',' or '{}', found {:?}", close, self.input));
+                    };
+                    self.input = rest;
+                    match self.frames.last_mut().unwrap() {
+                        Frame::Array { next_index, state } => {
+                            *next_index += 1;
+                            *state = SeqState::AfterComma;
+                        }
+                        Frame::Object { state } => *state = SeqState::AfterComma,
+                    }
+                    continue;
+                }
+            }
+
+            match self.frames.last() {
+                None => {
+                    if self.started {
+                        self.finished = true;
+                        if !self.input.is_empty() {
+                            return self.error(format!("trailing characters: {:?}", self.input));
+                        }
+                        return None;
+                    }
+                    self.started = true;
+                    return self.parse_value();
+                }
+                Some(Frame::Array { next_index, state }) => {
+                    let index = *next_index;
+                    let allow_close = matches!(state, SeqState::Start);
+                    if self.input.starts_with(']') {
+                        if !allow_close {
+                            return self.error("trailing comma before ']'");
+                        }
+                        self.input = &self.input[1..];
+                        self.frames.pop();
+                        return Some(JsonEvent::ArrayEnd);
+                    }
+                    self.stack.push(StackElement::Index(index));
+                    if let Some(Frame::Array { state, .. }) = self.frames.last_mut() {
+                        *state = SeqState::AfterValue;
+                    }
+                    return self.parse_value();
+                }
+                Some(Frame::Object { state }) => {
+                    let allow_close = matches!(state, SeqState::Start);
+                    if self.input.starts_with('}') {
+                        if !allow_close {
+                            return self.error("trailing comma before '}'");
+                        }
+                        self.input = &self.input[1..];
+                        self.frames.pop();
+                        return Some(JsonEvent::ObjectEnd);
+                    }
+                    let key = match string_literal(self.input) {
+                        Ok((rest, key)) => {
+                            self.input = rest.trim_start();
+                            key
+                        }
+                        Err(_) => return self.error("expected a string key"),
+                    };
+                    let Some(rest) = self.input.strip_prefix(':') else {
+                        return self.error("expected ':' after object key");
+                    };
+                    self.input = rest;
+                    self.stack.push(StackElement::Key(key));
+                    if let Some(Frame::Object { state }) = self.frames.last_mut() {
+                        *state = SeqState::AfterValue;
+                    }
+                    return self.parse_value();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsonEvent, Parser, StackElement};
+
+    #[test]
+    fn test_events_for_scalar() {
+        let events: Vec<_> = Parser::new("42").collect();
+        assert_eq!(events, vec![JsonEvent::NumberValue(42.0)]);
+    }
+
+    #[test]
+    fn test_events_for_array() {
+        let events: Vec<_> = Parser::new("[1, null, true]").collect();
+        assert_eq!(
+            events,
+            vec![
+                JsonEvent::ArrayStart,
+                JsonEvent::NumberValue(1.0),
+                JsonEvent::NullValue,
+                JsonEvent::BooleanValue(true),
+                JsonEvent::ArrayEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_events_for_object_track_stack() {
+        let mut parser = Parser::new("{\"outer\": {\"inner\": 1}}");
+        assert_eq!(parser.next(), Some(JsonEvent::ObjectStart));
+        assert_eq!(parser.next(), Some(JsonEvent::ObjectStart));
+        assert_eq!(parser.stack(), &[StackElement::Key("outer".into())]);
+        assert_eq!(parser.next(), Some(JsonEvent::NumberValue(1.0)));
+        assert_eq!(
+            parser.stack(),
+            &[
+                StackElement::Key("outer".into()),
+                StackElement::Key("inner".into())
+            ]
+        );
+        assert_eq!(parser.next(), Some(JsonEvent::ObjectEnd));
+        assert_eq!(parser.next(), Some(JsonEvent::ObjectEnd));
+        assert_eq!(parser.next(), None);
+    }
+
+    #[test]
+    fn test_trailing_comma_is_an_error() {
+        let events: Vec<_> = Parser::new("[1, 2,]").collect();
+        assert!(matches!(events.last(), Some(JsonEvent::Error(_))));
+    }
+}
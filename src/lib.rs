@@ -0,0 +1,463 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take},
+    character::complete::{char, digit1, multispace0, none_of},
+    combinator::{map, map_res, opt, recognize},
+    error::{ErrorKind, FromExternalError, ParseError as NomParseError},
+    multi::fold_many0,
+    sequence::{delimited, preceded, tuple},
+    Err as NomErr, IResult,
+};
+use std::fs;
+
+pub mod decode;
+pub mod encoder;
+pub mod error;
+pub mod event;
+
+use error::{ErrorCode, ParseError, ReadError};
+
+#[derive(PartialEq, Debug)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+/// A nom-compatible error that records the [`ErrorCode`] a failure should be
+/// reported as, alongside the `&str` suffix where it happened. Every
+/// production below either dispatches on the next byte or reports a failure
+/// at the position it was called with, so (unlike a blind `alt` whose
+/// default error-merging keeps only the last-tried branch) the code and
+/// position that reach the top are always the ones from the real point of
+/// failure.
+#[derive(Debug, Clone)]
+struct Tracked<'a> {
+    input: &'a str,
+    code: Option<ErrorCode>,
+}
+
+impl<'a> NomParseError<&'a str> for Tracked<'a> {
+    fn from_error_kind(input: &'a str, _kind: ErrorKind) -> Self {
+        Tracked { input, code: None }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a, E2> FromExternalError<&'a str, E2> for Tracked<'a> {
+    fn from_external_error(input: &'a str, _kind: ErrorKind, _e: E2) -> Self {
+        Tracked { input, code: None }
+    }
+}
+
+type JResult<'a, O> = IResult<&'a str, O, Tracked<'a>>;
+
+/// `char(c)`, typed so it can anchor inference at call sites (e.g. inside an
+/// `if let`) where the surrounding expression doesn't otherwise pin `Tracked`
+/// as the error type.
+fn tok<'a>(c: char) -> impl Fn(&'a str) -> JResult<'a, char> {
+    char(c)
+}
+
+fn fail_with<'a, O>(input: &'a str, code: ErrorCode) -> JResult<'a, O> {
+    Err(NomErr::Failure(Tracked {
+        input,
+        code: Some(code),
+    }))
+}
+
+/// What a position expecting more grammar (a comma, a colon, a value) should
+/// report when it finds neither: end-of-input if nothing is left, otherwise
+/// the unexpected byte that's actually there.
+fn expect_continuation<'a>(rest: &'a str) -> NomErr<Tracked<'a>> {
+    let code = if rest.is_empty() {
+        ErrorCode::EOFWhileParsingValue
+    } else {
+        ErrorCode::UnexpectedToken
+    };
+    NomErr::Failure(Tracked {
+        input: rest,
+        code: Some(code),
+    })
+}
+
+fn json_null(input: &str) -> JResult<'_, Json> {
+    map(tag("null"), |_| Json::Null)(input)
+}
+
+fn json_bool(input: &str) -> JResult<'_, Json> {
+    let json_true = map(tag("true"), |_| Json::Bool(true));
+    let json_false = map(tag("false"), |_| Json::Bool(false));
+    alt((json_true, json_false))(input)
+}
+
+fn json_number(input: &str) -> JResult<'_, Json> {
+    let integer_or_fraction = alt((
+        recognize(tuple((digit1, opt(tuple((char('.'), digit1)))))),
+        recognize(tuple((char('.'), digit1))),
+    ));
+    let exponent = tuple((
+        alt((char('e'), char('E'))),
+        opt(alt((char('+'), char('-')))),
+        digit1,
+    ));
+    let result: JResult<Json> = map_res(
+        recognize(tuple((opt(char('-')), integer_or_fraction, opt(exponent)))),
+        |s: &str| s.parse::<f64>().map(Json::Number),
+    )(input);
+    result.map_err(|_| {
+        NomErr::Failure(Tracked {
+            input,
+            code: Some(ErrorCode::InvalidNumber),
+        })
+    })
+}
+
+fn unicode_escape(input: &str) -> JResult<'_, u16> {
+    preceded(
+        char('u'),
+        map_res(take(4usize), |s: &str| u16::from_str_radix(s, 16)),
+    )(input)
+}
+
+fn unicode_char(input: &str) -> JResult<'_, char> {
+    let fail = |input| {
+        NomErr::Failure(Tracked {
+            input,
+            code: Some(ErrorCode::UnexpectedToken),
+        })
+    };
+
+    let (input, high) = unicode_escape(input)?;
+    if (0xDC00..=0xDFFF).contains(&high) {
+        return Err(fail(input));
+    }
+    if !(0xD800..=0xDBFF).contains(&high) {
+        let c = char::from_u32(high as u32).ok_or_else(|| fail(input))?;
+        return Ok((input, c));
+    }
+
+    let (input, _) = tag("\\")(input)?;
+    let (input, low) = unicode_escape(input)?;
+    if !(0xDC00..=0xDFFF).contains(&low) {
+        return Err(fail(input));
+    }
+    let code_point = 0x10000 + (((high as u32 - 0xD800) << 10) | (low as u32 - 0xDC00));
+    let c = char::from_u32(code_point).ok_or_else(|| fail(input))?;
+    Ok((input, c))
+}
+
+fn escape_sequence(input: &str) -> JResult<'_, char> {
+    preceded(
+        char('\\'),
+        alt((
+            map(char('"'), |_| '"'),
+            map(char('\\'), |_| '\\'),
+            map(char('/'), |_| '/'),
+            map(char('b'), |_| '\u{0008}'),
+            map(char('f'), |_| '\u{000C}'),
+            map(char('n'), |_| '\n'),
+            map(char('r'), |_| '\r'),
+            map(char('t'), |_| '\t'),
+            unicode_char,
+        )),
+    )(input)
+}
+
+fn string_literal(input: &str) -> JResult<'_, String> {
+    let string_char = alt((escape_sequence, none_of("\"\\")));
+    delimited(
+        char('"'),
+        fold_many0(string_char, String::new, |mut acc, c| {
+            acc.push(c);
+            acc
+        }),
+        char('"'),
+    )(input)
+}
+
+fn json_string(input: &str) -> JResult<'_, Json> {
+    map(string_literal, Json::String)(input)
+}
+
+/// Caps how deeply arrays/objects may nest inside one another. `json_value`,
+/// `json_array`, and `json_object` are mutually recursive over the call
+/// stack, so without a bound, deeply nested-but-valid input overflows the
+/// stack and aborts the process — unlike a `panic!`, not something a caller
+/// can recover from with `Result` or even `catch_unwind`.
+const MAX_NESTING_DEPTH: usize = 500;
+
+fn json_array(input: &str, depth: usize) -> JResult<'_, Json> {
+    let (input, _) = tok('[')(input)?;
+    let (input, _) = multispace0(input)?;
+    if let Ok((input, _)) = tok(']')(input) {
+        return Ok((input, Json::Array(Vec::new())));
+    }
+
+    let (mut input, first) = json_value(input, depth)?;
+    let mut items = vec![first];
+    loop {
+        let (rest, _) = multispace0(input)?;
+        if let Ok((rest, _)) = tok(']')(rest) {
+            return Ok((rest, Json::Array(items)));
+        }
+        let (rest, _) = tok(',')(rest).map_err(|_| expect_continuation(rest))?;
+        let (rest, _) = multispace0(rest)?;
+        let (rest, value) = json_value(rest, depth)?;
+        items.push(value);
+        input = rest;
+    }
+}
+
+fn json_object(input: &str, depth: usize) -> JResult<'_, Json> {
+    let (input, _) = tok('{')(input)?;
+    let (input, _) = multispace0(input)?;
+    if let Ok((input, _)) = tok('}')(input) {
+        return Ok((input, Json::Object(Vec::new())));
+    }
+
+    let (mut input, first) = json_object_entry(input, depth)?;
+    let mut fields = vec![first];
+    loop {
+        let (rest, _) = multispace0(input)?;
+        if let Ok((rest, _)) = tok('}')(rest) {
+            return Ok((rest, Json::Object(fields)));
+        }
+        let (rest, _) = tok(',')(rest).map_err(|_| expect_continuation(rest))?;
+        let (rest, _) = multispace0(rest)?;
+        let (rest, entry) = json_object_entry(rest, depth)?;
+        fields.push(entry);
+        input = rest;
+    }
+}
+
+/// Parses a single `"key": value` pair, reporting [`ErrorCode::KeyMustBeAString`]
+/// when the key position holds something other than a string literal instead
+/// of falling through to a generic token error.
+fn json_object_entry(input: &str, depth: usize) -> JResult<'_, (String, Json)> {
+    if !input.starts_with('"') {
+        return fail_with(input, ErrorCode::KeyMustBeAString);
+    }
+    let (input, key) = string_literal(input)?;
+    let (input, _) = multispace0(input)?;
+    let (input, _) = tok(':')(input).map_err(|_| expect_continuation(input))?;
+    let (input, _) = multispace0(input)?;
+    let (input, value) = json_value(input, depth)?;
+    Ok((input, (key, value)))
+}
+
+/// Dispatches on the next byte instead of trying every production in turn:
+/// the JSON grammar is LL(1), so the next byte always identifies a unique
+/// production, and a failure inside it is never masked by a sibling
+/// production's unrelated, shallower failure.
+fn json_value(input: &str, depth: usize) -> JResult<'_, Json> {
+    match input.as_bytes().first() {
+        Some(b'n') => json_null(input),
+        Some(b't') | Some(b'f') => json_bool(input),
+        Some(b'"') => json_string(input),
+        Some(b'[') => {
+            let depth = depth + 1;
+            if depth > MAX_NESTING_DEPTH {
+                return fail_with(input, ErrorCode::RecursionLimitExceeded);
+            }
+            json_array(input, depth)
+        }
+        Some(b'{') => {
+            let depth = depth + 1;
+            if depth > MAX_NESTING_DEPTH {
+                return fail_with(input, ErrorCode::RecursionLimitExceeded);
+            }
+            json_object(input, depth)
+        }
+        Some(b'-' | b'.' | b'0'..=b'9') => json_number(input),
+        Some(_) => fail_with(input, ErrorCode::UnexpectedToken),
+        None => fail_with(input, ErrorCode::EOFWhileParsingValue),
+    }
+}
+
+fn json(input: &str) -> JResult<'_, Json> {
+    let (rest, value) = json_value(input, 0)?;
+    if !rest.is_empty() {
+        return fail_with(rest, ErrorCode::TrailingCharacters);
+    }
+    Ok((rest, value))
+}
+
+/// Parses `input` as a whole JSON document, reporting a [`ParseError`] with
+/// a byte offset and line/column instead of panicking on malformed input.
+pub fn parse(input: &str) -> Result<Json, ParseError> {
+    json(input)
+        .map(|(_, json)| json)
+        .map_err(|err| to_parse_error(input, err))
+}
+
+fn to_parse_error(source: &str, err: NomErr<Tracked>) -> ParseError {
+    match err {
+        NomErr::Incomplete(_) => ParseError::at(ErrorCode::EOFWhileParsingValue, source, ""),
+        NomErr::Error(e) | NomErr::Failure(e) => {
+            ParseError::at(e.code.unwrap_or(ErrorCode::UnexpectedToken), source, e.input)
+        }
+    }
+}
+
+pub fn read_json_file(filename: &str) -> Result<Json, ReadError> {
+    let contents = fs::read_to_string(filename)?;
+    Ok(parse(&contents)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{ErrorCode, ReadError};
+    use crate::{
+        json_array, json_bool, json_null, json_number, json_object, json_string, parse,
+        read_json_file, Json, MAX_NESTING_DEPTH,
+    };
+
+    #[test]
+    fn test_json_null() {
+        assert_eq!(json_null("null").unwrap().1, Json::Null);
+    }
+
+    #[test]
+    fn test_json_bool() {
+        assert_eq!(json_bool("true").unwrap().1, Json::Bool(true));
+        assert_eq!(json_bool("false").unwrap().1, Json::Bool(false));
+    }
+
+    #[test]
+    fn test_json_number() {
+        assert_eq!(json_number("1").unwrap().1, Json::Number(1.0));
+        assert_eq!(json_number("123").unwrap().1, Json::Number(123.0));
+        assert_eq!(json_number("-383").unwrap().1, Json::Number(-383.0));
+        assert_eq!(json_number(".383").unwrap().1, Json::Number(0.383));
+        assert_eq!(json_number("-.383").unwrap().1, Json::Number(-0.383));
+        assert_eq!(json_number("-1.383").unwrap().1, Json::Number(-1.383));
+        assert_eq!(json_number("1e10").unwrap().1, Json::Number(1e10));
+        assert_eq!(json_number("1E-2").unwrap().1, Json::Number(1e-2));
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("\"\"").unwrap().1, Json::String("".into()));
+        assert_eq!(json_string("\"a\"").unwrap().1, Json::String("a".into()));
+    }
+
+    #[test]
+    fn test_json_string_escapes() {
+        assert_eq!(json_string("\"\\n\"").unwrap().1, Json::String("\n".into()));
+        assert_eq!(json_string("\"\\\"\"").unwrap().1, Json::String("\"".into()));
+        assert_eq!(json_string("\"\\\\\"").unwrap().1, Json::String("\\".into()));
+        assert_eq!(json_string("\"\\u0041\"").unwrap().1, Json::String("A".into()));
+        assert_eq!(
+            json_string("\"\\uD83D\\uDE00\"").unwrap().1,
+            Json::String("\u{1F600}".into())
+        );
+        assert!(json_string("\"\\uD800\"").is_err());
+    }
+
+    #[test]
+    fn test_json_array() {
+        assert_eq!(json_array("[]", 0).unwrap().1, Json::Array(vec![]));
+        assert_eq!(
+            json_array("[null]", 0).unwrap().1,
+            Json::Array(vec![Json::Null])
+        );
+        assert_eq!(
+            json_array("[null, true, 1, \"a\"]", 0).unwrap().1,
+            Json::Array(vec![
+                Json::Null,
+                Json::Bool(true),
+                Json::Number(1.0),
+                Json::String("a".into())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_json_object() {
+        assert_eq!(json_object("{}", 0).unwrap().1, Json::Object(vec![]));
+        assert_eq!(
+            json_object("{\"key\": \"value\"}", 0).unwrap().1,
+            Json::Object(vec![("key".into(), Json::String("value".into()))])
+        );
+        assert_eq!(
+            json_object("{\"outer\": {\"inner\": \"value\"}}", 0)
+                .unwrap()
+                .1,
+            Json::Object(vec![(
+                "outer".into(),
+                Json::Object(vec![("inner".into(), Json::String("value".into()))])
+            )])
+        );
+    }
+
+    #[test]
+    fn test_parse_ok() {
+        assert_eq!(parse("[1, 2, 3]").unwrap(), json_array("[1, 2, 3]", 0).unwrap().1);
+    }
+
+    #[test]
+    fn test_parse_rejects_trailing_comma() {
+        assert!(parse("[4, 9, \"ara\",]").is_err());
+    }
+
+    #[test]
+    fn test_parse_reports_trailing_characters() {
+        let err = parse("42 43").unwrap_err();
+        assert_eq!(err.code, ErrorCode::TrailingCharacters);
+        assert_eq!(err.offset, 2);
+        assert_eq!(err.column, 3);
+    }
+
+    #[test]
+    fn test_parse_reports_eof_while_parsing_value() {
+        let err = parse("").unwrap_err();
+        assert_eq!(err.code, ErrorCode::EOFWhileParsingValue);
+    }
+
+    #[test]
+    fn test_parse_reports_precise_position_in_nested_array() {
+        let err = parse("[1, 2, .]").unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidNumber);
+        assert_eq!(err.offset, 7);
+    }
+
+    #[test]
+    fn test_parse_reports_precise_position_in_nested_object() {
+        let err = parse("{\"a\": [1, .]}").unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidNumber);
+        assert_eq!(err.offset, 10);
+    }
+
+    #[test]
+    fn test_parse_reports_key_must_be_a_string() {
+        let err = parse("{1:2}").unwrap_err();
+        assert_eq!(err.code, ErrorCode::KeyMustBeAString);
+        assert_eq!(err.offset, 1);
+    }
+
+    #[test]
+    fn test_parse_rejects_deeply_nested_arrays_instead_of_overflowing_the_stack() {
+        let input = "[".repeat(5000) + &"]".repeat(5000);
+        let err = parse(&input).unwrap_err();
+        assert_eq!(err.code, ErrorCode::RecursionLimitExceeded);
+    }
+
+    #[test]
+    fn test_parse_allows_nesting_within_the_recursion_limit() {
+        let input = "[".repeat(MAX_NESTING_DEPTH) + &"]".repeat(MAX_NESTING_DEPTH);
+        assert!(parse(&input).is_ok());
+    }
+
+    #[test]
+    fn test_read_json_file_reports_io_error_instead_of_panicking() {
+        let err = read_json_file("/nonexistent/path/does-not-exist.json").unwrap_err();
+        assert!(matches!(err, ReadError::Io(_)));
+    }
+}